@@ -5,15 +5,32 @@ use alloc::{
     string::{String, ToString},
 };
 use core::{
-    cell::{Ref, RefCell},
+    cell::{Cell, Ref, RefCell},
     fmt::Debug,
     hash::{BuildHasher, Hash, Hasher},
     ops::Deref,
 };
+#[cfg(feature = "std")]
+use core::{
+    marker::PhantomData,
+    sync::atomic::{
+        AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
+        AtomicU64, AtomicU8, AtomicUsize, Ordering,
+    },
+};
 
 use ahash::RandomState;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
 use libafl_bolts::{ownedref::OwnedRef, Named};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::{
+    fs::{File, OpenOptions},
+    io::{Read as _, Seek, SeekFrom, Write as _},
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
 
 use super::Observer;
 use crate::{inputs::UsesInput, observers::ObserverWithHashField, Error};
@@ -185,3 +202,1132 @@ where
         Some(s.finish())
     }
 }
+
+/// A simple observer that captures a target's first write per execution.
+///
+/// Mirrors [`core::cell::OnceCell`] semantics: `pre_exec` clears the slot, and the first
+/// `set` call thereafter wins, while any later write in the same execution is ignored (and
+/// recorded via [`OnceCellValueObserver::wrote_multiple`]). It is built on a [`RefCell`]
+/// rather than `OnceCell` itself, so the slot can be cleared through the same shared
+/// reference the target writes through, the way [`RefCellValueObserver`] does. [`Self::hash`]
+/// returns `None` while the slot is empty, so a `HashFeedback` naturally treats "never
+/// written this execution" as uninteresting.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(bound = "T: serde::de::DeserializeOwned")]
+pub struct OnceCellValueObserver<'a, T>
+where
+    T: Debug + Serialize,
+{
+    /// The name of this observer.
+    name: String,
+    /// The value, `None` until the target writes it for the first time this execution.
+    pub value: OwnedRef<'a, RefCell<Option<T>>>,
+    /// Whether `set` was called more than once since the last `pre_exec`.
+    wrote_multiple: Cell<bool>,
+}
+
+impl<'a, T> OnceCellValueObserver<'a, T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    /// Creates a new [`OnceCellValueObserver`] with the given name.
+    #[must_use]
+    pub fn new(name: &'static str, value: &'a RefCell<Option<T>>) -> Self {
+        Self {
+            name: name.to_string(),
+            value: OwnedRef::Ref(value),
+            wrote_multiple: Cell::new(false),
+        }
+    }
+
+    /// Sets the value, unless it has already been set since the last `pre_exec`, in which
+    /// case the write is dropped and [`OnceCellValueObserver::wrote_multiple`] starts
+    /// returning `true`.
+    pub fn set(&self, new_value: T) {
+        let mut slot = self.value.as_ref().borrow_mut();
+        if slot.is_some() {
+            self.wrote_multiple.set(true);
+        } else {
+            *slot = Some(new_value);
+        }
+    }
+
+    /// Get a copy of the value, if the target has written it this execution.
+    #[must_use]
+    pub fn get(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.value.as_ref().borrow().clone()
+    }
+
+    /// Whether `set` was called more than once since the last `pre_exec`.
+    #[must_use]
+    pub fn wrote_multiple(&self) -> bool {
+        self.wrote_multiple.get()
+    }
+
+    /// Clone the current value out of this object.
+    #[must_use]
+    pub fn take(self) -> Option<T>
+    where
+        T: Clone,
+    {
+        match self.value {
+            OwnedRef::Ref(r) => r.borrow().clone(),
+            OwnedRef::Owned(v) => v.borrow().clone(),
+        }
+    }
+}
+
+/// Clears the slot so the target's next `set` call is treated as the first write.
+impl<'a, S, T> Observer<S> for OnceCellValueObserver<'a, T>
+where
+    S: UsesInput,
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn pre_exec(&mut self, _state: &mut S, _input: &S::Input) -> Result<(), Error> {
+        *self.value.as_ref().borrow_mut() = None;
+        self.wrote_multiple.set(false);
+        Ok(())
+    }
+}
+
+impl<'a, T> Named for OnceCellValueObserver<'a, T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<'a, T: Hash> ObserverWithHashField for OnceCellValueObserver<'a, T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn hash(&self) -> Option<u64> {
+        let slot = self.value.as_ref().borrow();
+        let value = slot.as_ref()?;
+        let mut s = RandomState::with_seeds(1, 2, 3, 4).build_hasher();
+        Hash::hash(value, &mut s);
+        Some(s.finish())
+    }
+}
+
+/// Computes the standard (IEEE, reflected) CRC-32 of `data`, used by
+/// [`PersistentValueObserver`] to detect a torn trailing journal record.
+#[cfg(feature = "std")]
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+/// Replays a [`PersistentValueObserver`] journal, returning the value of the last record
+/// whose CRC validates. A torn trailing record (as left behind by a crash mid-write) is
+/// detected and the file is truncated to the end of the last good record, so the next `set`
+/// appends cleanly.
+#[cfg(feature = "std")]
+fn replay_persistent_journal<T>(journal: &mut File) -> Result<Option<T>, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    journal
+        .seek(SeekFrom::Start(0))
+        .map_err(|e| Error::unknown(format!("failed to seek persistent value journal: {e}")))?;
+    let mut bytes = Vec::new();
+    journal
+        .read_to_end(&mut bytes)
+        .map_err(|e| Error::unknown(format!("failed to read persistent value journal: {e}")))?;
+
+    let mut offset = 0_usize;
+    let mut last_good = None;
+    let mut last_good_end = 0_usize;
+    while offset + 4 <= bytes.len() {
+        let len = u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) as usize;
+        let value_start = offset + 4;
+        let value_end = value_start + len;
+        let crc_end = value_end + 4;
+        if crc_end > bytes.len() {
+            // Torn trailing record: the process died before the record was fully written.
+            break;
+        }
+        let value_bytes = &bytes[value_start..value_end];
+        let stored_crc = u32::from_le_bytes(bytes[value_end..crc_end].try_into().unwrap());
+        if crc32(value_bytes) != stored_crc {
+            break;
+        }
+        let Ok(value) = postcard::from_bytes::<T>(value_bytes) else {
+            break;
+        };
+        last_good = Some(value);
+        last_good_end = crc_end;
+        offset = crc_end;
+    }
+
+    journal
+        .set_len(last_good_end as u64)
+        .map_err(|e| Error::unknown(format!("failed to truncate persistent value journal: {e}")))?;
+    journal
+        .seek(SeekFrom::End(0))
+        .map_err(|e| Error::unknown(format!("failed to seek persistent value journal: {e}")))?;
+
+    Ok(last_good)
+}
+
+/// A value observer whose writes are durably recorded to an append-only journal file, so a
+/// single observed value (a monotonic high-water mark, the maximum depth/size seen, a
+/// best-coverage score, ...) survives the fuzzer process being restarted after a crash or
+/// OOM.
+///
+/// Each `set` first appends a record `(len, postcard-serialized value, crc32)` to the journal
+/// and fsyncs it, and only afterwards updates the in-memory value, so a crash can never
+/// expose a value that was never logged. On construction, [`Self::new_persistent`] replays
+/// the journal and adopts the last record whose CRC validates, discarding a torn trailing
+/// record.
+///
+/// The journal `path` must belong to exactly one `PersistentValueObserver` instance at a
+/// time: construction truncates away anything after the last valid record, not only when
+/// that tail is actually torn, so a second process or observer appending to the same path
+/// concurrently (e.g. two clients of a multi-core campaign both pointed at one "shared"
+/// metric file) can have an in-flight write truncated away by the other's `open`. Give each
+/// process/observer its own journal path, for example by including the fuzzer's client id in
+/// it.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct PersistentValueObserver<T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    /// The name of this observer.
+    name: String,
+    /// The current value, already durably recorded in `journal`.
+    value: T,
+    /// Path to the journal file, kept so the observer can be re-opened after deserialization.
+    path: PathBuf,
+    /// The open journal file, positioned at its end.
+    journal: File,
+}
+
+#[cfg(feature = "std")]
+impl<T> PersistentValueObserver<T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    /// Creates a new [`PersistentValueObserver`] journaled at `path`, replaying any existing
+    /// journal to recover the value left by a previous run, or falling back to `initial` if
+    /// the journal is empty or does not yet exist.
+    ///
+    /// `path` must be exclusive to this observer instance; see the type-level documentation.
+    pub fn new_persistent(
+        name: &'static str,
+        path: impl AsRef<Path>,
+        initial: T,
+    ) -> Result<Self, Error> {
+        Self::open(name.to_string(), path.as_ref().to_path_buf(), initial)
+    }
+
+    fn open(name: String, path: PathBuf, fallback: T) -> Result<Self, Error> {
+        let mut journal = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .map_err(|e| {
+                Error::unknown(format!(
+                    "failed to open persistent value observer journal at {path:?}: {e}"
+                ))
+            })?;
+        let value = replay_persistent_journal::<T>(&mut journal)?.unwrap_or(fallback);
+        Ok(Self {
+            name,
+            value,
+            path,
+            journal,
+        })
+    }
+
+    /// Get a reference to the underlying value.
+    #[must_use]
+    pub fn get_ref(&self) -> &T {
+        &self.value
+    }
+
+    /// Durably journals `new_value`, flushing and fsyncing it to disk, and only then updates
+    /// the in-memory value.
+    pub fn set(&mut self, new_value: T) -> Result<(), Error> {
+        let bytes = postcard::to_allocvec(&new_value)
+            .map_err(|e| Error::unknown(format!("failed to serialize persistent value: {e}")))?;
+        let len = u32::try_from(bytes.len())
+            .map_err(|_| Error::unknown("persistent value is too large to journal"))?;
+        let crc = crc32(&bytes);
+
+        self.journal
+            .seek(SeekFrom::End(0))
+            .map_err(|e| Error::unknown(format!("failed to seek persistent value journal: {e}")))?;
+        self.journal
+            .write_all(&len.to_le_bytes())
+            .and_then(|()| self.journal.write_all(&bytes))
+            .and_then(|()| self.journal.write_all(&crc.to_le_bytes()))
+            .and_then(|()| self.journal.flush())
+            .map_err(|e| {
+                Error::unknown(format!("failed to write persistent value journal: {e}"))
+            })?;
+        // The record is on disk before the in-memory value changes, so a crash here can only
+        // ever lose an update we already logged, never expose one we did not.
+        self.journal.sync_all().map_err(|e| {
+            Error::unknown(format!("failed to fsync persistent value journal: {e}"))
+        })?;
+
+        self.value = new_value;
+        Ok(())
+    }
+
+    /// Clone or move the current value out of this object.
+    #[must_use]
+    pub fn take(self) -> T {
+        self.value
+    }
+}
+
+/// This *does not* reset the value inside the observer.
+#[cfg(feature = "std")]
+impl<S, T> Observer<S> for PersistentValueObserver<T>
+where
+    S: UsesInput,
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn pre_exec(&mut self, _state: &mut S, _input: &S::Input) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Named for PersistentValueObserver<T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Hash> ObserverWithHashField for PersistentValueObserver<T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn hash(&self) -> Option<u64> {
+        let mut s = RandomState::with_seeds(1, 2, 3, 4).build_hasher();
+        Hash::hash(&self.value, &mut s);
+        Some(s.finish())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Serialize for PersistentValueObserver<T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("PersistentValueObserver", 3)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("path", &self.path.to_string_lossy().into_owned())?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+
+/// Plain data used to deserialize a [`PersistentValueObserver`]: the journal file itself is
+/// re-opened (and replayed) at `path`, with `value` as the fallback if the journal turns out
+/// to be empty.
+#[cfg(feature = "std")]
+#[derive(Deserialize)]
+#[serde(bound = "T: serde::de::DeserializeOwned")]
+struct PersistentValueObserverData<T> {
+    name: String,
+    path: String,
+    value: T,
+}
+
+#[cfg(feature = "std")]
+impl<'de, T> Deserialize<'de> for PersistentValueObserver<T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = PersistentValueObserverData::<T>::deserialize(deserializer)?;
+        Self::open(data.name, PathBuf::from(data.path), data.value)
+            .map_err(|e| serde::de::Error::custom(format!("{e:?}")))
+    }
+}
+
+/// A simple observer with a single [`Cell`]'d `Copy` value.
+///
+/// For small `Copy` payloads, this avoids the dynamic borrow tracking [`RefCellValueObserver`]
+/// pays for but does not need: like [`Cell`] itself, `get`/`set` are single calls with no
+/// borrow bookkeeping. It also remembers the value as of the last `pre_exec`, so a feedback can
+/// cheaply ask "did this change during the run" via [`CellValueObserver::changed`] without
+/// cloning the value itself.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(bound = "T: serde::de::DeserializeOwned")]
+pub struct CellValueObserver<'a, T>
+where
+    T: Copy + Debug + Serialize,
+{
+    /// The name of this observer.
+    name: String,
+    /// The value.
+    pub value: OwnedRef<'a, Cell<T>>,
+    /// The value as of the last `pre_exec`, if any.
+    previous: Option<T>,
+}
+
+impl<'a, T> CellValueObserver<'a, T>
+where
+    T: Copy + Debug + Serialize + serde::de::DeserializeOwned,
+{
+    /// Creates a new [`CellValueObserver`] with the given name.
+    #[must_use]
+    pub fn new(name: &'static str, value: &'a Cell<T>) -> Self {
+        Self {
+            name: name.to_string(),
+            value: OwnedRef::Ref(value),
+            previous: None,
+        }
+    }
+
+    /// Get the current value.
+    #[must_use]
+    pub fn get(&self) -> T {
+        self.value.as_ref().get()
+    }
+
+    /// Set the value.
+    pub fn set(&self, new_value: T) {
+        self.value.as_ref().set(new_value);
+    }
+
+    /// The value as observed at the start of the run, i.e. at the last `pre_exec`.
+    #[must_use]
+    pub fn previous(&self) -> Option<T> {
+        self.previous
+    }
+
+    /// Whether the value changed since the last `pre_exec`, if a previous value is known.
+    #[must_use]
+    pub fn changed(&self) -> Option<bool>
+    where
+        T: PartialEq,
+    {
+        self.previous.map(|previous| previous != self.get())
+    }
+
+    /// Clone or move the current value out of this object.
+    #[must_use]
+    pub fn take(self) -> T {
+        self.value.as_ref().get()
+    }
+}
+
+/// Records the value at the start of the run so [`CellValueObserver::changed`] can later
+/// compare it to the value at the end of the run.
+impl<'a, S, T> Observer<S> for CellValueObserver<'a, T>
+where
+    S: UsesInput,
+    T: Copy + Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn pre_exec(&mut self, _state: &mut S, _input: &S::Input) -> Result<(), Error> {
+        self.previous = Some(self.get());
+        Ok(())
+    }
+}
+
+impl<'a, T> Named for CellValueObserver<'a, T>
+where
+    T: Copy + Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<'a, T: Hash> ObserverWithHashField for CellValueObserver<'a, T>
+where
+    T: Copy + Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn hash(&self) -> Option<u64> {
+        let mut s = RandomState::with_seeds(1, 2, 3, 4).build_hasher();
+        Hash::hash(&self.get(), &mut s);
+        Some(s.finish())
+    }
+}
+
+/// Backing storage for a [`SyncValueObserver`]: a cell that can be read and written from any
+/// thread through a shared reference. [`LockedCell`] works for any `T: Clone + Send + Sync`; primitive
+/// integer (and `bool`) types additionally get a lock-free impl directly on the matching
+/// `core::sync::atomic` type, selected by naming it as the observer's storage parameter.
+#[cfg(feature = "std")]
+pub trait SyncValueStorage<T>: Debug + Send + Sync {
+    /// Creates a new cell holding `value`.
+    fn new_cell(value: T) -> Self;
+    /// Reads the current value out of the cell.
+    fn get(&self) -> T;
+    /// Writes a new value into the cell.
+    fn set(&self, value: T);
+}
+
+/// The default [`SyncValueStorage`]: an [`RwLock`] behind a shared reference, usable for any
+/// `T: Clone + Send + Sync`. See [`SyncValueObserver`].
+#[derive(Debug)]
+#[cfg(feature = "std")]
+pub struct LockedCell<T>(RwLock<T>);
+
+#[cfg(feature = "std")]
+impl<T> SyncValueStorage<T> for LockedCell<T>
+where
+    T: Debug + Clone + Send + Sync,
+{
+    fn new_cell(value: T) -> Self {
+        LockedCell(RwLock::new(value))
+    }
+
+    fn get(&self) -> T {
+        // A worker thread writing this value is exactly the kind of thread fuzzing
+        // legitimately crashes; recover from poison instead of bricking every later
+        // observation once that first panic happens while the write lock is held.
+        self.0
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    fn set(&self, value: T) {
+        *self
+            .0
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = value;
+    }
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_atomic_value_storage {
+    ($t:ty, $atomic:ty) => {
+        impl SyncValueStorage<$t> for $atomic {
+            fn new_cell(value: $t) -> Self {
+                <$atomic>::new(value)
+            }
+
+            fn get(&self) -> $t {
+                <$atomic>::load(self, Ordering::SeqCst)
+            }
+
+            fn set(&self, value: $t) {
+                <$atomic>::store(self, value, Ordering::SeqCst);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_atomic_value_storage!(u8, AtomicU8);
+#[cfg(feature = "std")]
+impl_atomic_value_storage!(u16, AtomicU16);
+#[cfg(feature = "std")]
+impl_atomic_value_storage!(u32, AtomicU32);
+#[cfg(feature = "std")]
+impl_atomic_value_storage!(u64, AtomicU64);
+#[cfg(feature = "std")]
+impl_atomic_value_storage!(usize, AtomicUsize);
+#[cfg(feature = "std")]
+impl_atomic_value_storage!(i8, AtomicI8);
+#[cfg(feature = "std")]
+impl_atomic_value_storage!(i16, AtomicI16);
+#[cfg(feature = "std")]
+impl_atomic_value_storage!(i32, AtomicI32);
+#[cfg(feature = "std")]
+impl_atomic_value_storage!(i64, AtomicI64);
+#[cfg(feature = "std")]
+impl_atomic_value_storage!(isize, AtomicIsize);
+#[cfg(feature = "std")]
+impl_atomic_value_storage!(bool, AtomicBool);
+
+/// A simple observer with a single value that may be read and written from any thread.
+///
+/// Unlike [`ValueObserver`] and [`RefCellValueObserver`], which rely on single-threaded
+/// interior mutability, this observer stores its value behind an `Arc` of a
+/// [`SyncValueStorage`] cell so the target can write to it from a worker thread while the
+/// fuzzer reads it from the main thread in `post_exec`. The default storage, [`LockedCell`],
+/// wraps the value in an [`RwLock`]; for a primitive integer or `bool`, name the matching
+/// `core::sync::atomic` type instead (e.g. `SyncValueObserver<u64, AtomicU64>`) to get a
+/// lock-free cell. Requires the `std` feature.
+#[derive(Debug)]
+#[cfg(feature = "std")]
+pub struct SyncValueObserver<T, C = LockedCell<T>>
+where
+    T: Debug + Serialize,
+    C: SyncValueStorage<T>,
+{
+    /// The name of this observer.
+    name: String,
+    /// The value, shared between threads.
+    value: Arc<C>,
+    phantom: PhantomData<T>,
+}
+
+#[cfg(feature = "std")]
+impl<T, C> Clone for SyncValueObserver<T, C>
+where
+    T: Debug + Serialize,
+    C: SyncValueStorage<T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            value: self.value.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, C> SyncValueObserver<T, C>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+    C: SyncValueStorage<T>,
+{
+    /// Creates a new [`SyncValueObserver`] with the given name, wrapping `value` in its
+    /// storage cell `C` behind an `Arc` that can be cloned and handed to another thread.
+    #[must_use]
+    pub fn new(name: &'static str, value: T) -> Self {
+        Self {
+            name: name.to_string(),
+            value: Arc::new(C::new_cell(value)),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new [`SyncValueObserver`] sharing an already-constructed storage cell, for
+    /// example one also held by the harness thread that writes to it.
+    #[must_use]
+    pub fn with_storage(name: &'static str, value: Arc<C>) -> Self {
+        Self {
+            name: name.to_string(),
+            value,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a clone of the underlying `Arc` of the storage cell, so it can be shared with
+    /// another thread.
+    #[must_use]
+    pub fn storage(&self) -> Arc<C> {
+        self.value.clone()
+    }
+
+    /// Get a copy of the underlying value.
+    #[must_use]
+    pub fn get(&self) -> T {
+        self.value.get()
+    }
+
+    /// Set the value.
+    pub fn set(&self, new_value: T) {
+        self.value.set(new_value);
+    }
+
+    /// Read a copy of the current value out of this object, consuming it.
+    #[must_use]
+    pub fn take(self) -> T {
+        match Arc::try_unwrap(self.value) {
+            Ok(cell) => cell.get(),
+            Err(shared) => shared.get(),
+        }
+    }
+}
+
+/// This *does not* reset the value inside the observer.
+#[cfg(feature = "std")]
+impl<S, T, C> Observer<S> for SyncValueObserver<T, C>
+where
+    S: UsesInput,
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+    C: SyncValueStorage<T>,
+{
+    fn pre_exec(&mut self, _state: &mut S, _input: &S::Input) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, C> Named for SyncValueObserver<T, C>
+where
+    T: Debug + Serialize,
+    C: SyncValueStorage<T>,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Hash, C> ObserverWithHashField for SyncValueObserver<T, C>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+    C: SyncValueStorage<T>,
+{
+    fn hash(&self) -> Option<u64> {
+        let mut s = RandomState::with_seeds(1, 2, 3, 4).build_hasher();
+        Hash::hash(&self.value.get(), &mut s);
+        Some(s.finish())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, C> Serialize for SyncValueObserver<T, C>
+where
+    T: Debug + Serialize,
+    C: SyncValueStorage<T>,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SyncValueObserver", 2)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("value", &self.value.get())?;
+        state.end()
+    }
+}
+
+/// Plain data used to (de)serialize a [`SyncValueObserver`]; the storage cell itself is not
+/// serializable, so deserializing creates a fresh one holding the serialized value.
+#[cfg(feature = "std")]
+#[derive(Deserialize)]
+#[serde(bound = "T: serde::de::DeserializeOwned")]
+struct SyncValueObserverData<T> {
+    name: String,
+    value: T,
+}
+
+#[cfg(feature = "std")]
+impl<'de, T, C> Deserialize<'de> for SyncValueObserver<T, C>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+    C: SyncValueStorage<T>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = SyncValueObserverData::<T>::deserialize(deserializer)?;
+        Ok(Self {
+            name: data.name,
+            value: Arc::new(C::new_cell(data.value)),
+            phantom: PhantomData,
+        })
+    }
+}
+
+/// Starts building a [`ProjectedValueObserver`] that owns `owner` itself, for when the
+/// fuzzer (rather than the target) owns the struct of interest and only a single field of it
+/// needs to be observed. Finish with [`OwningValueObserver::project`].
+///
+/// This is a free function rather than `ValueObserver::owning`: neither its arguments nor its
+/// return type mention `ValueObserver`'s own `'a`/`T`, so calling it as an associated function
+/// on `ValueObserver<'a, T>` would leave those parameters unconstrained and fail to compile
+/// (`error[E0282]: type annotations needed`) without a turbofish the caller has no reason to
+/// supply.
+#[must_use]
+pub fn owning<O>(name: &'static str, owner: O) -> OwningValueObserver<O> {
+    OwningValueObserver {
+        name: name.to_string(),
+        owner: Box::new(owner),
+    }
+}
+
+/// Intermediate builder returned by [`owning`]; call [`Self::project`] to pick the field that
+/// should actually be observed.
+#[derive(Debug)]
+pub struct OwningValueObserver<O> {
+    name: String,
+    owner: Box<O>,
+}
+
+impl<O> OwningValueObserver<O> {
+    /// Projects the owned `O` down to the single field `&T` that should be observed, moving
+    /// the owner into the resulting [`ProjectedValueObserver`].
+    #[must_use]
+    pub fn project<T>(self, project: impl FnOnce(&O) -> &T) -> ProjectedValueObserver<O, T>
+    where
+        T: Debug + Serialize + serde::de::DeserializeOwned,
+    {
+        // SAFETY: `owner` is heap-allocated, so its address is stable no matter how the `Box`
+        // handle itself (or the `ProjectedValueObserver` built around it) is subsequently
+        // moved. `projected` stays valid for as long as `owner` is kept alive next to it,
+        // which `ProjectedValueObserver` guarantees by storing them together.
+        let projected: *const T = project(&self.owner);
+        ProjectedValueObserver {
+            name: self.name,
+            owner: self.owner,
+            projected,
+        }
+    }
+}
+
+/// An observer that owns a struct `O` while watching a single field `&T` projected out of it.
+///
+/// [`ValueObserver`] can only borrow a `&'a T` that must outlive the observer, which is
+/// awkward when the fuzzer, not the target, owns the struct of interest and needs to move it
+/// around. This observer instead owns `O` itself behind a [`Box`] (so its heap address is
+/// stable) and stores a raw pointer projected into it by a one-time closure -- the same
+/// "owner derefs to a stable address" technique the `owning_ref` crate documents -- so the
+/// whole observer can be moved freely while [`Deref`] still reaches the projected field.
+/// Build one with [`owning`], e.g. `owning(name, big_struct).project(|s| &s.counter)`.
+///
+/// Note this type intentionally does not implement `Serialize`/`Deserialize`: `O` is an
+/// arbitrary fuzzer-owned type that cannot in general be reconstructed from the projected
+/// `T` alone.
+#[derive(Debug)]
+pub struct ProjectedValueObserver<O, T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    /// The name of this observer.
+    name: String,
+    /// The owner kept alive alongside `projected`; never read directly other than through
+    /// [`Self::owner`], but must outlive `projected`.
+    owner: Box<O>,
+    /// Raw pointer into `owner`, produced once by the closure passed to
+    /// [`OwningValueObserver::project`].
+    projected: *const T,
+}
+
+impl<O, T> ProjectedValueObserver<O, T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    /// Get a reference to the owner, e.g. to read other fields of it.
+    #[must_use]
+    pub fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    /// Get a reference to the projected value.
+    #[must_use]
+    pub fn get_ref(&self) -> &T {
+        self
+    }
+
+    /// Clone the current value out of this object.
+    #[must_use]
+    pub fn take(self) -> T
+    where
+        T: Clone,
+    {
+        self.get_ref().clone()
+    }
+}
+
+impl<O, T> Deref for ProjectedValueObserver<O, T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: `projected` was derived from `&*self.owner` in `OwningValueObserver::project`
+        // and `owner` is kept alive for at least as long as `self`.
+        unsafe { &*self.projected }
+    }
+}
+
+/// This *does not* reset the value inside the observer.
+impl<S, O, T> Observer<S> for ProjectedValueObserver<O, T>
+where
+    S: UsesInput,
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn pre_exec(&mut self, _state: &mut S, _input: &S::Input) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<O, T> Named for ProjectedValueObserver<O, T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<O, T: Hash> ObserverWithHashField for ProjectedValueObserver<O, T>
+where
+    T: Debug + Serialize + serde::de::DeserializeOwned,
+{
+    fn hash(&self) -> Option<u64> {
+        let mut s = RandomState::with_seeds(1, 2, 3, 4).build_hasher();
+        Hash::hash(self.get_ref(), &mut s);
+        Some(s.finish())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod sync_value_observer_tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn locked_cell_get_set_and_hash_round_trip() {
+        let obs = SyncValueObserver::<u64>::new("sync", 1);
+        assert_eq!(obs.get(), 1);
+        obs.set(42);
+        assert_eq!(obs.get(), 42);
+        assert!(ObserverWithHashField::hash(&obs).is_some());
+    }
+
+    #[test]
+    fn atomic_storage_get_and_set() {
+        let obs = SyncValueObserver::<u64, AtomicU64>::new("sync-atomic", 7);
+        assert_eq!(obs.get(), 7);
+        obs.set(9);
+        assert_eq!(obs.get(), 9);
+    }
+
+    #[test]
+    fn recovers_from_a_lock_poisoned_by_a_crashed_worker_thread() {
+        let obs = SyncValueObserver::<u64>::new("sync-poison", 1);
+        let storage = obs.storage();
+        let handle = thread::spawn(move || {
+            let _guard = storage.0.write().unwrap();
+            panic!("worker thread crashed while holding the write lock");
+        });
+        assert!(handle.join().is_err());
+
+        // Before the fix this `get` (and every later one) would panic on the poisoned lock,
+        // bricking every subsequent execution's observation after the first crash.
+        assert_eq!(obs.get(), 1);
+        obs.set(2);
+        assert_eq!(obs.get(), 2);
+    }
+}
+
+#[cfg(test)]
+mod cell_value_observer_tests {
+    use super::*;
+
+    #[test]
+    fn get_set_and_hash_round_trip() {
+        let cell = Cell::new(1_i32);
+        let obs = CellValueObserver::new("cell", &cell);
+        assert_eq!(obs.get(), 1);
+        obs.set(2);
+        assert_eq!(obs.get(), 2);
+        assert!(ObserverWithHashField::hash(&obs).is_some());
+    }
+
+    #[test]
+    fn changed_compares_against_the_last_pre_exec_snapshot() {
+        let cell = Cell::new(1_i32);
+        let mut obs = CellValueObserver::new("cell", &cell);
+        assert_eq!(obs.changed(), None);
+
+        // `Observer::pre_exec` just snapshots the current value into `previous`; poke the
+        // field directly rather than dragging in a full fuzzer `State` to call it through the
+        // trait.
+        obs.previous = Some(obs.get());
+        assert_eq!(obs.previous(), Some(1));
+        assert_eq!(obs.changed(), Some(false));
+
+        obs.set(5);
+        assert_eq!(obs.changed(), Some(true));
+    }
+}
+
+#[cfg(test)]
+mod once_cell_value_observer_tests {
+    use super::*;
+
+    #[test]
+    fn first_write_wins_and_hash_reflects_emptiness() {
+        let cell = RefCell::new(None);
+        let obs = OnceCellValueObserver::new("once", &cell);
+        assert_eq!(obs.get(), None);
+        assert_eq!(ObserverWithHashField::hash(&obs), None);
+
+        obs.set(1);
+        assert_eq!(obs.get(), Some(1));
+        assert!(!obs.wrote_multiple());
+        assert!(ObserverWithHashField::hash(&obs).is_some());
+
+        // A second write in the same execution is dropped, but flagged.
+        obs.set(2);
+        assert_eq!(obs.get(), Some(1));
+        assert!(obs.wrote_multiple());
+    }
+
+    #[test]
+    fn pre_exec_clears_the_slot_for_the_next_execution() {
+        let cell = RefCell::new(None);
+        let obs = OnceCellValueObserver::new("once", &cell);
+        obs.set(1);
+        obs.set(2);
+        assert!(obs.wrote_multiple());
+
+        // `Observer::pre_exec` clears the slot and the multiple-writes flag; make the same two
+        // assignments here rather than dragging in a full fuzzer `State` to call it.
+        *obs.value.as_ref().borrow_mut() = None;
+        obs.wrote_multiple.set(false);
+        assert_eq!(obs.get(), None);
+        assert!(!obs.wrote_multiple());
+
+        obs.set(3);
+        assert_eq!(obs.get(), Some(3));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod persistent_value_observer_tests {
+    use std::sync::atomic::{AtomicU32, Ordering as AtomicOrdering};
+
+    use super::*;
+
+    /// A journal path unique to the calling test, cleaned up on drop so tests don't leak
+    /// files into the system temp directory or collide with each other when run in parallel.
+    struct TempJournalPath(PathBuf);
+
+    impl TempJournalPath {
+        fn new(tag: &str) -> Self {
+            static COUNTER: AtomicU32 = AtomicU32::new(0);
+            let unique = COUNTER.fetch_add(1, AtomicOrdering::Relaxed);
+            let mut path = std::env::temp_dir();
+            path.push(format!(
+                "libafl-persistent-value-observer-test-{tag}-{}-{unique}.journal",
+                std::process::id()
+            ));
+            Self(path)
+        }
+    }
+
+    impl Drop for TempJournalPath {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn get_set_and_hash_round_trip() {
+        let path = TempJournalPath::new("round-trip");
+        let mut obs =
+            PersistentValueObserver::new_persistent("persistent", &path.0, 1_u32).unwrap();
+        assert_eq!(*obs.get_ref(), 1);
+        assert!(ObserverWithHashField::hash(&obs).is_some());
+
+        obs.set(2).unwrap();
+        assert_eq!(*obs.get_ref(), 2);
+        assert_eq!(obs.take(), 2);
+    }
+
+    #[test]
+    fn recovers_the_last_value_across_a_restart() {
+        let path = TempJournalPath::new("restart");
+        {
+            let mut obs =
+                PersistentValueObserver::new_persistent("persistent", &path.0, 0_u32).unwrap();
+            obs.set(1).unwrap();
+            obs.set(2).unwrap();
+            obs.set(3).unwrap();
+        }
+
+        // Simulate the process restarting: reopen at the same path with a different fallback,
+        // and confirm the journaled value wins over it.
+        let reopened =
+            PersistentValueObserver::new_persistent("persistent", &path.0, 99_u32).unwrap();
+        assert_eq!(*reopened.get_ref(), 3);
+    }
+
+    #[test]
+    fn discards_a_torn_trailing_record_left_by_a_crash() {
+        let path = TempJournalPath::new("torn-record");
+        {
+            let mut obs =
+                PersistentValueObserver::new_persistent("persistent", &path.0, 0_u32).unwrap();
+            obs.set(1).unwrap();
+            obs.set(2).unwrap();
+        }
+        let good_len = std::fs::metadata(&path.0).unwrap().len();
+
+        // Append a record whose length prefix promises more payload bytes than are actually
+        // present, and no CRC at all, the way a crash mid-`set` would leave the file.
+        {
+            let mut journal = OpenOptions::new().append(true).open(&path.0).unwrap();
+            let claimed_len: u32 = 64;
+            journal.write_all(&claimed_len.to_le_bytes()).unwrap();
+            journal.write_all(&[0xAB, 0xCD]).unwrap();
+        }
+        let torn_len = std::fs::metadata(&path.0).unwrap().len();
+        assert!(torn_len > good_len);
+
+        let reopened =
+            PersistentValueObserver::new_persistent("persistent", &path.0, 99_u32).unwrap();
+        // The last fully-written, CRC-valid record still wins...
+        assert_eq!(*reopened.get_ref(), 2);
+        // ...and the torn tail was truncated away so future `set`s append cleanly.
+        let truncated_len = std::fs::metadata(&path.0).unwrap().len();
+        assert_eq!(truncated_len, good_len);
+    }
+}
+
+#[cfg(test)]
+mod projected_value_observer_tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Owner {
+        counter: u32,
+        #[allow(dead_code)]
+        label: String,
+    }
+
+    #[test]
+    fn derefs_to_the_projected_field_and_survives_a_move() {
+        let owner = Owner {
+            counter: 1,
+            label: "big struct".to_string(),
+        };
+        let obs = owning("projected", owner).project(|o| &o.counter);
+        assert_eq!(*obs, 1);
+        assert_eq!(obs.owner().counter, 1);
+
+        // Move the observer into a new binding (and a `Vec`, for good measure): the projected
+        // pointer targets the heap allocation behind `owner`'s `Box`, not the `Owner` value's
+        // original stack slot, so it must keep dereferencing correctly after the move.
+        let moved = obs;
+        let mut container = Vec::new();
+        container.push(moved);
+        let reprojected = container.pop().unwrap();
+
+        assert_eq!(*reprojected, 1);
+        assert_eq!(reprojected.owner().counter, 1);
+        assert!(ObserverWithHashField::hash(&reprojected).is_some());
+        assert_eq!(reprojected.take(), 1);
+    }
+}